@@ -209,4 +209,24 @@ mod erc20 {
             Ok(())
         }
     }
+
+    impl Erc20 {
+        /// Returns the package version encoded as `major * 10000 + minor * 100 + patch`.
+        #[ink(message, selector = "0xec6d41e1")]
+        pub fn version(&self) -> u32 {
+            let part = |v: &str| v.parse::<u32>().unwrap_or(0);
+            part(env!("CARGO_PKG_VERSION_MAJOR")) * 10_000
+                + part(env!("CARGO_PKG_VERSION_MINOR")) * 100
+                + part(env!("CARGO_PKG_VERSION_PATCH"))
+        }
+
+        /// Returns the contract name and its package version.
+        #[ink(message, selector = "0x666ca1c6")]
+        pub fn contract_metadata(&self) -> (String, String) {
+            (
+                String::from(env!("CARGO_PKG_NAME")),
+                String::from(env!("CARGO_PKG_VERSION")),
+            )
+        }
+    }
 }
@@ -28,11 +28,24 @@ mod erc20 {
         OnlyOwnerAccess,
         InvalidNewOwner,
         NotBlacklistedUser,
+        /// Returned if the requested operation is paused.
+        OperationPaused,
+        /// Returned if a pause mask contains unknown operation bits.
+        InvalidPauseOps,
     }
 
     /// The ERC-20 result type.
     pub type Result<T> = core::result::Result<T, Error>;
 
+    /// Pausable operation: `transfer` and `transfer_from`.
+    pub const PAUSE_TRANSFER: u32 = 0b001;
+    /// Pausable operation: `mint`.
+    pub const PAUSE_MINT: u32 = 0b010;
+    /// Pausable operation: `burn`.
+    pub const PAUSE_BURN: u32 = 0b100;
+    /// All pausable operations.
+    pub const PAUSE_ALL: u32 = PAUSE_TRANSFER | PAUSE_MINT | PAUSE_BURN;
+
     /// Base contract which allows children to implement an emergency stop mechanism.
     #[ink::trait_definition]
     pub trait Pausable {
@@ -47,6 +60,18 @@ mod erc20 {
         /// Return contract pause statue.
         #[ink(message)]
         fn pause_state(&self) -> bool;
+
+        /// Pause the operations in `ops`, a bitmask of `PAUSE_*` flags.
+        #[ink(message)]
+        fn pause_ops(&mut self, ops: u32) -> Result<()>;
+
+        /// Recover the operations in `ops`, a bitmask of `PAUSE_*` flags.
+        #[ink(message)]
+        fn unpause_ops(&mut self, ops: u32) -> Result<()>;
+
+        /// Return the bitmask of paused operations.
+        #[ink(message)]
+        fn paused_ops(&self) -> u32;
     }
 
     #[ink::trait_definition]
@@ -83,8 +108,8 @@ mod erc20 {
         symbol: Option<String>,
         /// Decimals of the token
         decimals: Option<u8>,
-        /// Implement an emergency stop mechanism.
-        pause: bool,
+        /// Implement an emergency stop mechanism, a bitmask of paused `PAUSE_*` operations.
+        paused_ops: u32,
         /// The contract owner, provides basic authorization control
         /// functions, this simplifies the implementation of "user permissions".
         owner: AccountId,
@@ -116,10 +141,16 @@ mod erc20 {
     }
 
     #[ink(event)]
-    pub struct Pause {}
+    pub struct Pause {
+        #[ink(topic)]
+        ops: u32,
+    }
 
     #[ink(event)]
-    pub struct Unpause {}
+    pub struct Unpause {
+        #[ink(topic)]
+        ops: u32,
+    }
 
     #[ink(event)]
     pub struct DestroyedBlackFunds {
@@ -175,7 +206,7 @@ mod erc20 {
                 name,
                 symbol,
                 decimals,
-                pause: false,
+                paused_ops: 0,
                 owner: caller,
                 blacklisted: Default::default(),
             };
@@ -312,30 +343,57 @@ mod erc20 {
         /// Pause contract transaction.
         #[ink(message)]
         fn pause(&mut self) -> Result<()> {
+            self.pause_ops(PAUSE_ALL)
+        }
+
+        /// Recover paused contract.
+        #[ink(message)]
+        fn unpause(&mut self) -> Result<()> {
+            self.unpause_ops(PAUSE_ALL)
+        }
+
+        /// Return contract pause statue, `true` if any operation is paused.
+        #[ink(message)]
+        fn pause_state(&self) -> bool {
+            self.paused_ops != 0
+        }
+
+        /// Pause the operations in `ops`, a bitmask of `PAUSE_*` flags.
+        #[ink(message)]
+        fn pause_ops(&mut self, ops: u32) -> Result<()> {
             self.only_owner();
+            if ops & !PAUSE_ALL != 0 {
+                return Err(Error::InvalidPauseOps);
+            }
 
-            if !self.pause {
-                self.pause = true;
-                self.env().emit_event(Pause {})
+            let newly_paused = ops & !self.paused_ops;
+            if newly_paused != 0 {
+                self.paused_ops |= newly_paused;
+                self.env().emit_event(Pause { ops: newly_paused })
             }
             Ok(())
         }
 
-        /// Recover paused contract.
+        /// Recover the operations in `ops`, a bitmask of `PAUSE_*` flags.
         #[ink(message)]
-        fn unpause(&mut self) -> Result<()> {
+        fn unpause_ops(&mut self, ops: u32) -> Result<()> {
             self.only_owner();
-            if self.pause {
-                self.pause = false;
-                self.env().emit_event(Unpause {})
+            if ops & !PAUSE_ALL != 0 {
+                return Err(Error::InvalidPauseOps);
+            }
+
+            let newly_unpaused = ops & self.paused_ops;
+            if newly_unpaused != 0 {
+                self.paused_ops &= !newly_unpaused;
+                self.env().emit_event(Unpause { ops: newly_unpaused })
             }
             Ok(())
         }
 
-        /// Return contract pause statue.
+        /// Return the bitmask of paused operations.
         #[ink(message)]
-        fn pause_state(&self) -> bool {
-            self.pause
+        fn paused_ops(&self) -> u32 {
+            self.paused_ops
         }
     }
 
@@ -383,9 +441,12 @@ mod erc20 {
     impl Erc20 {
         /// Mint a new amount of tokens
         /// these tokens are deposited into the owner address
-        #[ink(message)]
+        #[ink(message, selector = "0xcfdd9aa2")]
         pub fn mint(&mut self, user: AccountId, amount: Balance) -> Result<()> {
             self.only_owner();
+            if self.is_paused(PAUSE_MINT) {
+                return Err(Error::OperationPaused);
+            }
             assert_ne!(user, Default::default());
             if amount <= 0 {
                 return Err(Error::InvalidAmount);
@@ -402,9 +463,12 @@ mod erc20 {
         /// These tokens are withdrawn from the owner address
         /// if the balance must be enough to cover the redeem
         /// or the call will fail.
-        #[ink(message)]
+        #[ink(message, selector = "0xb1efc17b")]
         pub fn burn(&mut self, user: AccountId, amount: Balance) -> Result<()> {
             self.only_owner();
+            if self.is_paused(PAUSE_BURN) {
+                return Err(Error::OperationPaused);
+            }
             if *self.total_supply < amount {
                 return Err(Error::InsufficientSupply);
             }
@@ -427,12 +491,17 @@ mod erc20 {
         ///
         /// Returns `InsufficientBalance` error if there are not enough tokens on
         /// the caller's account balance.
+        ///
+        /// # Panics
+        ///
+        /// If transfers are paused, since `IResult` has no paused error.
         fn transfer_from_to(
             &mut self,
             from: AccountId,
             to: AccountId,
             value: Balance,
         ) -> IResult<()> {
+            assert!(!self.is_paused(PAUSE_TRANSFER));
             let from_balance = self.balance_of(from);
             if from_balance < value {
                 return Err(IError::InsufficientBalance);
@@ -448,8 +517,32 @@ mod erc20 {
             Ok(())
         }
 
+        fn is_paused(&self, op: u32) -> bool {
+            self.paused_ops & op != 0
+        }
+
         fn only_owner(&self) {
             assert_eq!(self.env().caller(), self.owner);
         }
     }
+
+    impl Erc20 {
+        /// Returns the package version encoded as `major * 10000 + minor * 100 + patch`.
+        #[ink(message, selector = "0xec6d41e1")]
+        pub fn version(&self) -> u32 {
+            let part = |v: &str| v.parse::<u32>().unwrap_or(0);
+            part(env!("CARGO_PKG_VERSION_MAJOR")) * 10_000
+                + part(env!("CARGO_PKG_VERSION_MINOR")) * 100
+                + part(env!("CARGO_PKG_VERSION_PATCH"))
+        }
+
+        /// Returns the contract name and its package version.
+        #[ink(message, selector = "0x666ca1c6")]
+        pub fn contract_metadata(&self) -> (String, String) {
+            (
+                String::from(env!("CARGO_PKG_NAME")),
+                String::from(env!("CARGO_PKG_VERSION")),
+            )
+        }
+    }
 }
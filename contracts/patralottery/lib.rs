@@ -155,7 +155,7 @@ mod patralottery {
             }
         }
 
-        #[ink(message, payable)]
+        #[ink(message, payable, selector = "0xe216e90c")]
         pub fn buy_tickets(&mut self, epoch_id: EpochID, num: Vec<u32>, amount: u32) {
             let ret: BabeRandomness = self.env().extension().next_epoch();
             assert!(epoch_id >= ret.epoch + 1);
@@ -216,7 +216,7 @@ mod patralottery {
             })
         }
 
-        #[ink(message)]
+        #[ink(message, selector = "0x48864b12")]
         pub fn draw_lottery(&mut self, epoch_id: EpochID) {
             let random_hash;
             let next_random: BabeRandomness = self.env().extension().next_epoch();
@@ -340,7 +340,7 @@ mod patralottery {
         }
 
         /// Return the account bought lotteries for the specified `owner`.
-        #[ink(message)]
+        #[ink(message, selector = "0xfdf85bbf")]
         pub fn lotteries_of(&self, owner: AccountId) -> Vec<MyLottery> {
             let mut my_lotteries = vec![];
             if let Some(epochs) = self.buyers.get(&owner) {
@@ -361,7 +361,7 @@ mod patralottery {
             my_lotteries
         }
 
-        #[ink(message)]
+        #[ink(message, selector = "0x717a29cb")]
         pub fn epoch_history(&self, epoch_id: EpochID) -> Option<Lottery> {
             if let Some(lottery) = self.epochs.get(&epoch_id) {
                 Some((*lottery).clone())
@@ -370,7 +370,7 @@ mod patralottery {
             }
         }
 
-        #[ink(message)]
+        #[ink(message, selector = "0x32d82910")]
         pub fn latest_epoch(&self) -> EpochInfo {
             let ret: BabeRandomness = self.env().extension().next_epoch();
             EpochInfo {
@@ -382,13 +382,13 @@ mod patralottery {
             }
         }
 
-        #[ink(message)]
+        #[ink(message, selector = "0xae6fd1ec")]
         pub fn biggest_winner(&self) -> Vec<BiggestWinner> {
             self.winners.clone()
         }
 
         /// The historical randomness function cant get the current epoch and next epoch randomness.
-        #[ink(message)]
+        #[ink(message, selector = "0x13210043")]
         pub fn randomness_of(&self, epoch_id: EpochID) -> (String, Vec<u32>) {
             let random_hash;
             let next_random: BabeRandomness = self.env().extension().next_epoch();
@@ -403,14 +403,14 @@ mod patralottery {
             Self::get_winning_number(random_hash)
         }
 
-        #[ink(message)]
+        #[ink(message, selector = "0x2667f4a6")]
         pub fn current_randomness(&self) -> (String, Vec<u32>) {
             let cur_random: BabeRandomness = self.env().extension().current_epoch();
             let ret = Hash::from(cur_random.randomness);
             Self::get_winning_number(ret)
         }
 
-        #[ink(message)]
+        #[ink(message, selector = "0xf00cbd1e")]
         pub fn next_randomness(&self) -> (String, Vec<u32>) {
             let next_random: BabeRandomness = self.env().extension().next_epoch();
             let ret = Hash::from(next_random.randomness);
@@ -445,4 +445,24 @@ mod patralottery {
             (seed, win)
         }
     }
+
+    impl PatraLottery {
+        /// Returns the package version encoded as `major * 10000 + minor * 100 + patch`.
+        #[ink(message, selector = "0xec6d41e1")]
+        pub fn version(&self) -> u32 {
+            let part = |v: &str| v.parse::<u32>().unwrap_or(0);
+            part(env!("CARGO_PKG_VERSION_MAJOR")) * 10_000
+                + part(env!("CARGO_PKG_VERSION_MINOR")) * 100
+                + part(env!("CARGO_PKG_VERSION_PATCH"))
+        }
+
+        /// Returns the contract name and its package version.
+        #[ink(message, selector = "0x666ca1c6")]
+        pub fn contract_metadata(&self) -> (String, String) {
+            (
+                String::from(env!("CARGO_PKG_NAME")),
+                String::from(env!("CARGO_PKG_VERSION")),
+            )
+        }
+    }
 }
@@ -6,7 +6,7 @@ use ink_lang as ink;
 mod patramaker {
     use dai::Erc20;
     use ink_env::call::FromAccountId;
-    use ink_prelude::vec::Vec;
+    use ink_prelude::{string::String, vec::Vec};
     use ink_storage::{
         collections::HashMap as StorageMap,
         traits::{PackedLayout, SpreadLayout},
@@ -138,35 +138,35 @@ mod patramaker {
         }
 
         /// Adjust Min Collateral Ratio only admin
-        #[ink(message)]
+        #[ink(message, selector = "0xe3cc04bd")]
         pub fn adjust_mcr(&mut self, mcr: u32) {
             self.only_owner();
             self.min_collateral_ratio = mcr;
         }
 
         // Adjust Min Liquidation Ratio only admin
-        #[ink(message)]
+        #[ink(message, selector = "0x4bad7a34")]
         pub fn adjust_mlr(&mut self, mlr: u32) {
             self.only_owner();
             self.min_liquidation_ratio = mlr;
         }
 
         /// Adjust Liquidater Reward Ratio only admin
-        #[ink(message)]
+        #[ink(message, selector = "0x1d152194")]
         pub fn adjust_lrr(&mut self, lrr: u32) {
             self.only_owner();
             self.liquidater_reward_ratio = lrr;
         }
 
         /// Adjust dot price only admin
-        #[ink(message)]
+        #[ink(message, selector = "0xdf6f4748")]
         pub fn adjust_dot_price(&mut self, price: USD) {
             self.only_owner();
             self.dot_price = price;
         }
 
         /// System params
-        #[ink(message)]
+        #[ink(message, selector = "0x85b3e1f7")]
         pub fn system_params(&self) -> (u32, u32, u32, u32) {
             (
                 self.min_collateral_ratio,
@@ -177,13 +177,13 @@ mod patramaker {
         }
 
         /// Query cdp by id
-        #[ink(message)]
+        #[ink(message, selector = "0xffb46c88")]
         pub fn query_cdp(&self, cdp_id: CdpId) -> Option<CDP> {
             self.cdps.get(&cdp_id).cloned().and_then(|cdp| Some(cdp))
         }
 
         /// Stake collateral and issue dai
-        #[ink(message, payable)]
+        #[ink(message, payable, selector = "0xffede79b")]
         pub fn issue_dai(&mut self, cr: u32) -> (CdpId, Balance) {
             assert!(cr >= self.min_collateral_ratio);
             let caller = self.env().caller();
@@ -210,7 +210,7 @@ mod patramaker {
         }
 
         /// Only issuer can add collateral and update collateral ratio
-        #[ink(message, payable)]
+        #[ink(message, payable, selector = "0xe3d1fc1b")]
         pub fn add_collateral(&mut self, cdp_id: CdpId) {
             assert!(self.cdps.contains_key(&cdp_id));
             let caller = self.env().caller();
@@ -237,7 +237,7 @@ mod patramaker {
         }
 
         /// Only issuer can minus collateral and update collateral ratio
-        #[ink(message)]
+        #[ink(message, selector = "0xe794c7c6")]
         pub fn minus_collateral(&mut self, cdp_id: CdpId, collateral: Balance) {
             assert!(self.cdps.contains_key(&cdp_id));
             let caller = self.env().caller();
@@ -262,7 +262,7 @@ mod patramaker {
         }
 
         /// Only issuer can withdraw
-        #[ink(message)]
+        #[ink(message, selector = "0xf5a4f097")]
         pub fn withdraw_dot(&mut self, cdp_id: CdpId, dai: Balance) -> Balance {
             assert!(self.cdps.contains_key(&cdp_id));
             let caller = self.env().caller();
@@ -291,7 +291,7 @@ mod patramaker {
         }
 
         /// Anyone can invoke collateral liquidation if current collateral ratio lower than minimum
-        #[ink(message)]
+        #[ink(message, selector = "0xaea53649")]
         pub fn liquidate_collateral(&mut self, cdp_id: CdpId, dai: Balance) {
             assert!(self.cdps.contains_key(&cdp_id));
             let cdp = self.cdps.get_mut(&cdp_id).unwrap();
@@ -332,7 +332,7 @@ mod patramaker {
         }
 
         /// Returns the total issuers、total collateral、total issue dai.
-        #[ink(message)]
+        #[ink(message, selector = "0xdb6375a8")]
         pub fn total_supply(&self) -> (u32, Balance, Balance) {
             let mut issuers = Vec::new();
             let total_collateral: Balance = self.env().balance();
@@ -348,7 +348,7 @@ mod patramaker {
         }
 
         /// Returns the total cdp amount.
-        #[ink(message)]
+        #[ink(message, selector = "0x6e2a7d4c")]
         pub fn cdp_count(&self) -> u32 {
             self.cdp_count
         }
@@ -357,4 +357,24 @@ mod patramaker {
             assert_eq!(self.env().caller(), self.owner);
         }
     }
+
+    impl PatraMaker {
+        /// Returns the package version encoded as `major * 10000 + minor * 100 + patch`.
+        #[ink(message, selector = "0xec6d41e1")]
+        pub fn version(&self) -> u32 {
+            let part = |v: &str| v.parse::<u32>().unwrap_or(0);
+            part(env!("CARGO_PKG_VERSION_MAJOR")) * 10_000
+                + part(env!("CARGO_PKG_VERSION_MINOR")) * 100
+                + part(env!("CARGO_PKG_VERSION_PATCH"))
+        }
+
+        /// Returns the contract name and its package version.
+        #[ink(message, selector = "0x666ca1c6")]
+        pub fn contract_metadata(&self) -> (String, String) {
+            (
+                String::from(env!("CARGO_PKG_NAME")),
+                String::from(env!("CARGO_PKG_VERSION")),
+            )
+        }
+    }
 }
@@ -40,7 +40,7 @@ mod patrapixel {
         }
 
         /// Get and returns pixel metadata
-        #[ink(message)]
+        #[ink(message, selector = "0x6394e97a")]
         pub fn metadata(&self) -> Vec<(u32, u8)> {
             let mut vec = vec![];
             for (k, v) in self.metadata.iter() {
@@ -49,18 +49,18 @@ mod patrapixel {
             vec
         }
 
-        #[ink(message)]
+        #[ink(message, selector = "0xd92c9b21")]
         pub fn pool(&self) -> Balance {
             self.pool
         }
 
-        #[ink(message)]
+        #[ink(message, selector = "0x5bcd7160")]
         pub fn size(&self) -> (u32, u32) {
             self.size
         }
 
         /// update pixel with metadata
-        #[ink(message, payable)]
+        #[ink(message, payable, selector = "0x5f234f5d")]
         pub fn update(&mut self, points: Vec<(u32, u8)>) {
             assert!(points.len() > 0);
             let cost = self.env().transferred_balance();
@@ -78,4 +78,24 @@ mod patrapixel {
             });
         }
     }
+
+    impl Patrapixel {
+        /// Returns the package version encoded as `major * 10000 + minor * 100 + patch`.
+        #[ink(message, selector = "0xec6d41e1")]
+        pub fn version(&self) -> u32 {
+            let part = |v: &str| v.parse::<u32>().unwrap_or(0);
+            part(env!("CARGO_PKG_VERSION_MAJOR")) * 10_000
+                + part(env!("CARGO_PKG_VERSION_MINOR")) * 100
+                + part(env!("CARGO_PKG_VERSION_PATCH"))
+        }
+
+        /// Returns the contract name and its package version.
+        #[ink(message, selector = "0x666ca1c6")]
+        pub fn contract_metadata(&self) -> (String, String) {
+            (
+                String::from(env!("CARGO_PKG_NAME")),
+                String::from(env!("CARGO_PKG_VERSION")),
+            )
+        }
+    }
 }
@@ -176,7 +176,7 @@ mod patrapk {
         }
 
         // salt_hash = Hash("salt-rock/paper/scissors")
-        #[ink(message, payable)]
+        #[ink(message, payable, selector = "0xab700a1b")]
         pub fn create(&mut self, salt_hash: Hash) -> GameID {
             let mut game = GameDetails::default();
             game.creator = self.env().caller();
@@ -193,7 +193,7 @@ mod patrapk {
             self.counter
         }
 
-        #[ink(message)]
+        #[ink(message, selector = "0x55ddc8ac")]
         pub fn delete(&mut self, game_id: GameID) {
             let game = self.games.get(&game_id).unwrap();
             let caller = self.env().caller();
@@ -211,7 +211,7 @@ mod patrapk {
             });
         }
 
-        #[ink(message, payable)]
+        #[ink(message, payable, selector = "0x2c254e82")]
         pub fn join(&mut self, game_id: GameID, choice: Choice) {
             let caller = self.env().caller();
             let value = self.env().transferred_balance();
@@ -234,7 +234,7 @@ mod patrapk {
             });
         }
 
-        #[ink(message)]
+        #[ink(message, selector = "0xa80532c0")]
         pub fn reveal(&mut self, game_id: GameID, salt: String, choice: Choice) {
             let game = self.games.get(&game_id).unwrap();
             assert_eq!(game.status, GameStatus::Settle, "Cannot Reveal");
@@ -270,7 +270,7 @@ mod patrapk {
             self.env().emit_event(PKReveal { game_id, result });
         }
 
-        #[ink(message)]
+        #[ink(message, selector = "0xb2c68550")]
         pub fn expire(&mut self, game_id: GameID) {
             let game = self.games.get(&game_id).unwrap();
             assert_eq!(game.status, GameStatus::Settle, "Cannot Expire");
@@ -292,7 +292,7 @@ mod patrapk {
             });
         }
 
-        #[ink(message)]
+        #[ink(message, selector = "0xf330e016")]
         pub fn salt_hash(&self, salt: String, choice: Choice) -> Hash {
             let choice_str = match choice {
                 Choice::Rock => "rock",
@@ -305,20 +305,20 @@ mod patrapk {
             Hash::from(self.env().hash_bytes::<Blake2x256>(salt.as_bytes()))
         }
 
-        #[ink(message)]
+        #[ink(message, selector = "0xc8b89bf8")]
         pub fn game_of(&self, game_id: GameID) -> Result<GameDetails> {
             let game = self.games.get(&game_id).ok_or(Error::GameNotFound)?;
             Ok(game.clone())
         }
 
-        #[ink(message)]
+        #[ink(message, selector = "0x762072c9")]
         pub fn expire_of(&self, game_id: GameID) -> BlockNumber {
             let game = self.games.get(&game_id).unwrap();
             let epoch = self.env().block_number().saturating_sub(game.join_block);
             self.expire_time.saturating_sub(epoch)
         }
 
-        #[ink(message)]
+        #[ink(message, selector = "0xb1e1f2d5")]
         pub fn game_total(&self) -> u32 {
             self.counter
         }
@@ -346,4 +346,24 @@ mod patrapk {
             }
         }
     }
+
+    impl Patrapk {
+        /// Returns the package version encoded as `major * 10000 + minor * 100 + patch`.
+        #[ink(message, selector = "0xec6d41e1")]
+        pub fn version(&self) -> u32 {
+            let part = |v: &str| v.parse::<u32>().unwrap_or(0);
+            part(env!("CARGO_PKG_VERSION_MAJOR")) * 10_000
+                + part(env!("CARGO_PKG_VERSION_MINOR")) * 100
+                + part(env!("CARGO_PKG_VERSION_PATCH"))
+        }
+
+        /// Returns the contract name and its package version.
+        #[ink(message, selector = "0x666ca1c6")]
+        pub fn contract_metadata(&self) -> (String, String) {
+            (
+                String::from(env!("CARGO_PKG_NAME")),
+                String::from(env!("CARGO_PKG_VERSION")),
+            )
+        }
+    }
 }
@@ -100,32 +100,60 @@ mod exchange {
             }
         }
 
-        #[ink(message)]
-        pub fn swap_from_to_input(&mut self, from_sold: Balance) -> Balance {
+        /// Sell exactly `from_sold` from tokens, receiving at least `min_to_bought` to tokens.
+        /// Reverts once the block timestamp is past `deadline`.
+        #[ink(message, selector = 0x41ea6873)]
+        pub fn swap_from_to_input(
+            &mut self,
+            from_sold: Balance,
+            min_to_bought: Balance,
+            deadline: Timestamp,
+        ) -> Balance {
             let caller = self.env().caller();
-            self.token_from_to_input(from_sold, caller, caller)
+            self.token_from_to_input(from_sold, min_to_bought, deadline, caller, caller)
         }
 
-        #[ink(message)]
-        pub fn swap_to_from_input(&mut self, to_sold: Balance) -> Balance {
+        /// Sell exactly `to_sold` to tokens, receiving at least `min_from_bought` from tokens.
+        /// Reverts once the block timestamp is past `deadline`.
+        #[ink(message, selector = 0x0efa6ad0)]
+        pub fn swap_to_from_input(
+            &mut self,
+            to_sold: Balance,
+            min_from_bought: Balance,
+            deadline: Timestamp,
+        ) -> Balance {
             let caller = self.env().caller();
-            self.token_to_from_input(to_sold, caller, caller)
+            self.token_to_from_input(to_sold, min_from_bought, deadline, caller, caller)
         }
 
-        #[ink(message)]
-        pub fn swap_to_from_output(&mut self, from_bought: Balance) -> Balance {
+        /// Buy exactly `from_bought` from tokens, selling at most `max_to_sold` to tokens.
+        /// Reverts once the block timestamp is past `deadline`.
+        #[ink(message, selector = 0xc0eb1de9)]
+        pub fn swap_to_from_output(
+            &mut self,
+            from_bought: Balance,
+            max_to_sold: Balance,
+            deadline: Timestamp,
+        ) -> Balance {
             let caller = self.env().caller();
-            return self.token_to_from_output(from_bought, caller, caller);
+            return self.token_to_from_output(from_bought, max_to_sold, deadline, caller, caller);
         }
 
-        #[ink(message)]
-        pub fn swap_from_to_output(&mut self, to_bought: Balance) -> Balance {
+        /// Buy exactly `to_bought` to tokens, selling at most `max_from_sold` from tokens.
+        /// Reverts once the block timestamp is past `deadline`.
+        #[ink(message, selector = 0xb272a2d7)]
+        pub fn swap_from_to_output(
+            &mut self,
+            to_bought: Balance,
+            max_from_sold: Balance,
+            deadline: Timestamp,
+        ) -> Balance {
             let caller = self.env().caller();
-            self.token_from_to_output(to_bought, caller, caller)
+            self.token_from_to_output(to_bought, max_from_sold, deadline, caller, caller)
         }
 
         /// Public price function for from swap to Token trades with an exact input.
-        #[ink(message)]
+        #[ink(message, selector = 0xa135456d)]
         pub fn get_from_swap_to_input_price(&self, from_sold: Balance) -> Balance {
             assert!(from_sold > 0);
             let exchange_account = self.env().account_id();
@@ -135,7 +163,7 @@ mod exchange {
         }
 
         /// Public price function for to swap from Token trades with an exact input.
-        #[ink(message)]
+        #[ink(message, selector = 0x266fe735)]
         pub fn get_to_swap_from_input_price(&self, to_sold: Balance) -> Balance {
             assert!(to_sold > 0);
             let exchange_account = self.env().account_id();
@@ -145,7 +173,7 @@ mod exchange {
         }
 
         /// Public price function for DOT to Token trades with an exact output.
-        #[ink(message)]
+        #[ink(message, selector = 0x59ee06ae)]
         pub fn get_from_swap_to_output_price(&self, to_bought: Balance) -> Balance {
             assert!(to_bought > 0);
             let exchange_account = self.env().account_id();
@@ -155,7 +183,7 @@ mod exchange {
         }
 
         /// Public price function for Token to DOT trades with an exact output.
-        #[ink(message)]
+        #[ink(message, selector = 0xb27aaa07)]
         pub fn get_to_swap_from_output_price(&self, from_bought: Balance) -> Balance {
             assert!(from_bought > 0);
             let exchange_account = self.env().account_id();
@@ -167,14 +195,18 @@ mod exchange {
         fn token_to_from_input(
             &mut self,
             to_sold: Balance,
+            min_from_bought: Balance,
+            deadline: Timestamp,
             buyer: AccountId,
             recipient: AccountId,
         ) -> Balance {
             assert!(to_sold > 0);
+            assert!(self.env().block_timestamp() <= deadline);
             let exchange_account = self.env().account_id();
             let from_reserve: Balance = self.from_token_contract.balance_of(exchange_account);
             let to_reserve: Balance = self.to_token_contract.balance_of(exchange_account);
             let from_bought: Balance = Self::get_input_price(to_sold, to_reserve, from_reserve);
+            assert!(from_bought >= min_from_bought);
             assert!(self
                 .to_token_contract
                 .transfer_from(buyer, exchange_account, to_sold)
@@ -195,14 +227,18 @@ mod exchange {
         fn token_to_from_output(
             &mut self,
             from_bought: Balance,
+            max_to_sold: Balance,
+            deadline: Timestamp,
             buyer: AccountId,
             recipient: AccountId,
         ) -> Balance {
             assert!(from_bought > 0);
+            assert!(self.env().block_timestamp() <= deadline);
             let exchange_account = self.env().account_id();
             let from_reserve: Balance = self.from_token_contract.balance_of(exchange_account);
             let to_reserve: Balance = self.to_token_contract.balance_of(exchange_account);
             let to_sold: Balance = Self::get_output_price(from_bought, to_reserve, from_reserve);
+            assert!(to_sold <= max_to_sold);
             assert!(self
                 .to_token_contract
                 .transfer_from(buyer, exchange_account, to_sold)
@@ -223,14 +259,18 @@ mod exchange {
         fn token_from_to_input(
             &mut self,
             from_sold: Balance,
+            min_to_bought: Balance,
+            deadline: Timestamp,
             buyer: AccountId,
             recipient: AccountId,
         ) -> Balance {
             assert!(from_sold > 0);
+            assert!(self.env().block_timestamp() <= deadline);
             let exchange_account = self.env().account_id();
             let from_reserve: Balance = self.from_token_contract.balance_of(exchange_account);
             let to_reserve: Balance = self.to_token_contract.balance_of(exchange_account);
             let to_bought: Balance = Self::get_input_price(from_sold, from_reserve, to_reserve);
+            assert!(to_bought >= min_to_bought);
             assert!(self
                 .from_token_contract
                 .transfer_from(buyer, exchange_account, from_sold)
@@ -251,14 +291,18 @@ mod exchange {
         fn token_from_to_output(
             &mut self,
             to_bought: Balance,
+            max_from_sold: Balance,
+            deadline: Timestamp,
             buyer: AccountId,
             recipient: AccountId,
         ) -> Balance {
             assert!(to_bought > 0);
+            assert!(self.env().block_timestamp() <= deadline);
             let exchange_account = self.env().account_id();
             let from_reserve: Balance = self.from_token_contract.balance_of(exchange_account);
             let to_reserve: Balance = self.to_token_contract.balance_of(exchange_account);
             let from_sold: Balance = Self::get_output_price(to_bought, from_reserve, to_reserve);
+            assert!(from_sold <= max_from_sold);
             assert!(self
                 .from_token_contract
                 .transfer_from(buyer, exchange_account, from_sold)
@@ -281,7 +325,7 @@ mod exchange {
         /// Deposit DOT and Tokens (self.token) at current ratio to mint PAT tokens.
         // @return The amount of PAT minted.
         // 等比例添加
-        #[ink(message)]
+        #[ink(message, selector = 0x264cd04b)]
         pub fn add_liquidity(&mut self, from_tokens: Balance, to_tokens: Balance) -> Balance {
             let caller = self.env().caller();
             let exchange_account = self.env().account_id();
@@ -339,7 +383,7 @@ mod exchange {
         /// Burn PAT tokens to withdraw DOT and Tokens at current ratio.
         // @param amount Amount of PAT burned.
         // @return The amount of DOT and Tokens withdrawn.
-        #[ink(message)]
+        #[ink(message, selector = 0xbdd16bfa)]
         pub fn remove_liquidity(&mut self, lp_amount: Balance) -> (Balance, Balance) {
             assert!(lp_amount > 0);
             let total_liquidity = self.lp_token_contract.total_supply();
@@ -365,7 +409,7 @@ mod exchange {
             (from_amount, to_amount)
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0xddc30089)]
         pub fn estimated_add_liquidity(&self, from_tokens: Balance, to_tokens: Balance) -> Balance {
             let exchange_account = self.env().account_id();
             assert!(from_tokens > 0 && to_tokens > 0);
@@ -378,7 +422,7 @@ mod exchange {
             }
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0x36c655df)]
         pub fn estimated_remove_liquidity(&self, lp_amount: Balance) -> (Balance, Balance) {
             assert!(lp_amount > 0);
             let total_liquidity = self.lp_token_contract.total_supply();
@@ -391,7 +435,7 @@ mod exchange {
             (from_amount, to_amount)
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0x8453270b)]
         pub fn exchange_info(&self) -> ExchangeInfo {
             let caller = self.env().caller();
             let exchange_account = self.env().account_id();
@@ -413,18 +457,18 @@ mod exchange {
             }
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0x751f35b1)]
         pub fn lp_balance_of(&self, user: AccountId) -> Balance {
             self.lp_token_contract.balance_of(user)
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0xdec1d572)]
         pub fn lp_token_decimals(&self) -> u8 {
             self.lp_token_contract.token_decimals().unwrap_or(0)
         }
 
         /// estimated need to token amount by from tokens
-        #[ink(message)]
+        #[ink(message, selector = 0x7bbf9f94)]
         pub fn estimated_to_token(&self, from_tokens: Balance) -> Balance {
             let exchange_account = self.env().account_id();
             let from_reserve = self.from_token_contract.balance_of(exchange_account);
@@ -460,4 +504,24 @@ mod exchange {
             numerator / denominator + 1
         }
     }
+
+    impl PatraExchange {
+        /// Returns the package version encoded as `major * 10000 + minor * 100 + patch`.
+        #[ink(message, selector = 0xec6d41e1)]
+        pub fn version(&self) -> u32 {
+            let part = |v: &str| v.parse::<u32>().unwrap_or(0);
+            part(env!("CARGO_PKG_VERSION_MAJOR")) * 10_000
+                + part(env!("CARGO_PKG_VERSION_MINOR")) * 100
+                + part(env!("CARGO_PKG_VERSION_PATCH"))
+        }
+
+        /// Returns the contract name and its package version.
+        #[ink(message, selector = 0x666ca1c6)]
+        pub fn contract_metadata(&self) -> (String, String) {
+            (
+                String::from(env!("CARGO_PKG_NAME")),
+                String::from(env!("CARGO_PKG_VERSION")),
+            )
+        }
+    }
 }
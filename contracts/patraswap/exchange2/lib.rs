@@ -94,37 +94,70 @@ mod exchange {
             }
         }
 
-        #[ink(message, payable)]
-        pub fn swap_dot_to_token_input(&mut self) -> Balance {
+        /// Sell all transferred DOT, receiving at least `min_tokens` tokens.
+        /// Reverts once the block timestamp is past `deadline`.
+        #[ink(message, payable, selector = 0x2eb54715)]
+        pub fn swap_dot_to_token_input(
+            &mut self,
+            min_tokens: Balance,
+            deadline: Timestamp,
+        ) -> Balance {
             let caller = self.env().caller();
-            self.dot_to_token_input(self.env().transferred_balance(), caller, caller)
+            self.dot_to_token_input(
+                self.env().transferred_balance(),
+                min_tokens,
+                deadline,
+                caller,
+                caller,
+            )
         }
 
-        #[ink(message, payable)]
-        pub fn swap_dot_to_token_output(&mut self, tokens_bought: Balance) -> Balance {
+        /// Buy exactly `tokens_bought` tokens, selling at most the transferred DOT.
+        /// Unspent DOT is refunded. Reverts once the block timestamp is past `deadline`.
+        #[ink(message, payable, selector = 0x0aa29f69)]
+        pub fn swap_dot_to_token_output(
+            &mut self,
+            tokens_bought: Balance,
+            deadline: Timestamp,
+        ) -> Balance {
             let caller = self.env().caller();
             return self.dot_to_token_output(
                 tokens_bought,
                 self.env().transferred_balance(),
+                deadline,
                 caller,
                 caller,
             );
         }
 
-        #[ink(message)]
-        pub fn swap_token_to_dot_input(&mut self, tokens_sold: Balance) -> Balance {
+        /// Sell exactly `tokens_sold` tokens, receiving at least `min_dot` DOT.
+        /// Reverts once the block timestamp is past `deadline`.
+        #[ink(message, selector = 0x68309aab)]
+        pub fn swap_token_to_dot_input(
+            &mut self,
+            tokens_sold: Balance,
+            min_dot: Balance,
+            deadline: Timestamp,
+        ) -> Balance {
             let caller = self.env().caller();
-            self.token_to_dot_input(tokens_sold, caller, caller)
+            self.token_to_dot_input(tokens_sold, min_dot, deadline, caller, caller)
         }
 
-        #[ink(message)]
-        pub fn swap_token_to_dot_output(&mut self, dot_bought: Balance) -> Balance {
+        /// Buy exactly `dot_bought` DOT, selling at most `max_tokens` tokens.
+        /// Reverts once the block timestamp is past `deadline`.
+        #[ink(message, selector = 0x6d432351)]
+        pub fn swap_token_to_dot_output(
+            &mut self,
+            dot_bought: Balance,
+            max_tokens: Balance,
+            deadline: Timestamp,
+        ) -> Balance {
             let caller = self.env().caller();
-            self.token_to_dot_output(dot_bought, caller, caller)
+            self.token_to_dot_output(dot_bought, max_tokens, deadline, caller, caller)
         }
 
         /// Public price function for DOT to Token trades with an exact input.
-        #[ink(message)]
+        #[ink(message, selector = 0xc61c742a)]
         pub fn get_dot_to_token_input_price(&self, dot_sold: Balance) -> Balance {
             assert!(dot_sold > 0);
             let token_reserve: Balance = self.token_contract.balance_of(self.env().account_id());
@@ -132,7 +165,7 @@ mod exchange {
         }
 
         /// Public price function for DOT to Token trades with an exact output.
-        #[ink(message)]
+        #[ink(message, selector = 0x70186bbd)]
         pub fn get_dot_to_token_output_price(&self, tokens_bought: Balance) -> Balance {
             assert!(tokens_bought > 0);
             let token_reserve: Balance = self.token_contract.balance_of(self.env().account_id());
@@ -142,7 +175,7 @@ mod exchange {
         }
 
         /// Public price function for Token to DOT trades with an exact input.
-        #[ink(message)]
+        #[ink(message, selector = 0xc1d19df0)]
         pub fn get_token_to_dot_input_price(&self, tokens_sold: Balance) -> Balance {
             assert!(tokens_sold > 0);
             let token_reserve: Balance = self.token_contract.balance_of(self.env().account_id());
@@ -150,7 +183,7 @@ mod exchange {
         }
 
         /// Public price function for Token to DOT trades with an exact output.
-        #[ink(message)]
+        #[ink(message, selector = 0x3708772f)]
         pub fn get_token_to_dot_output_price(&self, dot_bought: Balance) -> Balance {
             assert!(dot_bought > 0);
             let token_reserve: Balance = self.token_contract.balance_of(self.env().account_id());
@@ -160,14 +193,18 @@ mod exchange {
         fn dot_to_token_input(
             &mut self,
             dot_sold: Balance,
+            min_tokens: Balance,
+            deadline: Timestamp,
             buyer: AccountId,
             recipient: AccountId,
         ) -> Balance {
             assert!(dot_sold > 0);
+            assert!(self.env().block_timestamp() <= deadline);
             let exchange_account = self.env().account_id();
             let token_reserve: Balance = self.token_contract.balance_of(exchange_account);
             let tokens_bought: Balance =
                 Self::get_input_price(dot_sold, self.dot_balance() - dot_sold, token_reserve);
+            assert!(tokens_bought >= min_tokens);
             assert!(self
                 .token_contract
                 .transfer(recipient, tokens_bought)
@@ -185,10 +222,12 @@ mod exchange {
             &mut self,
             tokens_bought: Balance,
             max_dot: Balance,
+            deadline: Timestamp,
             buyer: AccountId,
             recipient: AccountId,
         ) -> Balance {
             assert!(tokens_bought > 0 && max_dot > 0);
+            assert!(self.env().block_timestamp() <= deadline);
             let token_reserve: Balance = self.token_contract.balance_of(self.env().account_id());
             let dot_sold: Balance =
                 Self::get_output_price(tokens_bought, self.dot_balance() - max_dot, token_reserve);
@@ -212,14 +251,18 @@ mod exchange {
         fn token_to_dot_input(
             &mut self,
             tokens_sold: Balance,
+            min_dot: Balance,
+            deadline: Timestamp,
             buyer: AccountId,
             recipient: AccountId,
         ) -> Balance {
             assert!(tokens_sold > 0);
+            assert!(self.env().block_timestamp() <= deadline);
             let exchange_account = self.env().account_id();
             let token_reserve: Balance = self.token_contract.balance_of(exchange_account);
             let dot_bought: Balance =
                 Self::get_input_price(tokens_sold, token_reserve, self.env().balance());
+            assert!(dot_bought >= min_dot);
             assert!(self.env().transfer(recipient, dot_bought).is_ok());
             assert!(self
                 .token_contract
@@ -237,14 +280,18 @@ mod exchange {
         fn token_to_dot_output(
             &mut self,
             dot_bought: Balance,
+            max_tokens: Balance,
+            deadline: Timestamp,
             buyer: AccountId,
             recipient: AccountId,
         ) -> Balance {
             assert!(dot_bought > 0);
+            assert!(self.env().block_timestamp() <= deadline);
             let exchange_account = self.env().account_id();
             let token_reserve: Balance = self.token_contract.balance_of(exchange_account);
             let tokens_sold: Balance =
                 Self::get_output_price(dot_bought, token_reserve, self.env().balance());
+            assert!(tokens_sold <= max_tokens);
             assert!(self.env().transfer(recipient, dot_bought).is_ok());
             assert!(self
                 .token_contract
@@ -263,7 +310,7 @@ mod exchange {
     impl PatraExchange2 {
         /// Deposit DOT and Tokens (self.token) at current ratio to mint PAT tokens.
         // @return The amount of PAT minted.
-        #[ink(message, payable)]
+        #[ink(message, payable, selector = 0x264cd04b)]
         pub fn add_liquidity(&mut self, from_tokens: Balance) -> Balance {
             let caller = self.env().caller();
             let exchange_account = self.env().account_id();
@@ -313,7 +360,7 @@ mod exchange {
         /// Burn PAT tokens to withdraw DOT and Tokens at current ratio.
         // @param amount Amount of PAT burned.
         // @return The amount of DOT and Tokens withdrawn.
-        #[ink(message)]
+        #[ink(message, selector = 0xbdd16bfa)]
         pub fn remove_liquidity(&mut self, lp_amount: Balance) -> (Balance, Balance) {
             assert!(lp_amount > 0);
             let total_liquidity = self.lp_token_contract.total_supply();
@@ -336,7 +383,7 @@ mod exchange {
             (from_amount, to_amount)
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0xddc30089)]
         pub fn estimated_add_liquidity(&self, from_tokens: Balance, to_tokens: Balance) -> Balance {
             let exchange_account = self.env().account_id();
             assert!(from_tokens > 0 && to_tokens > 0);
@@ -349,7 +396,7 @@ mod exchange {
             }
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0x36c655df)]
         pub fn estimated_remove_liquidity(&self, lp_amount: Balance) -> (Balance, Balance) {
             assert!(lp_amount > 0);
             let total_liquidity = self.lp_token_contract.total_supply();
@@ -362,7 +409,7 @@ mod exchange {
             (from_amount, to_amount)
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0x8453270b)]
         pub fn exchange_info(&self) -> ExchangeInfo {
             let caller = self.env().caller();
             let exchange_account = self.env().account_id();
@@ -381,12 +428,12 @@ mod exchange {
             }
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0x751f35b1)]
         pub fn lp_balance_of(&self, user: AccountId) -> Balance {
             self.lp_token_contract.balance_of(user)
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0xdec1d572)]
         pub fn lp_token_decimals(&self) -> u8 {
             self.lp_token_contract.token_decimals().unwrap_or(0)
         }
@@ -396,7 +443,7 @@ mod exchange {
         }
 
         /// estimated need to token amount by from tokens
-        #[ink(message)]
+        #[ink(message, selector = 0x7bbf9f94)]
         pub fn estimated_to_token(&self, from_tokens: Balance) -> Balance {
             let exchange_account = self.env().account_id();
             let from_reserve = self.token_contract.balance_of(exchange_account);
@@ -432,4 +479,24 @@ mod exchange {
             numerator / denominator + 1
         }
     }
+
+    impl PatraExchange2 {
+        /// Returns the package version encoded as `major * 10000 + minor * 100 + patch`.
+        #[ink(message, selector = 0xec6d41e1)]
+        pub fn version(&self) -> u32 {
+            let part = |v: &str| v.parse::<u32>().unwrap_or(0);
+            part(env!("CARGO_PKG_VERSION_MAJOR")) * 10_000
+                + part(env!("CARGO_PKG_VERSION_MINOR")) * 100
+                + part(env!("CARGO_PKG_VERSION_PATCH"))
+        }
+
+        /// Returns the contract name and its package version.
+        #[ink(message, selector = 0x666ca1c6)]
+        pub fn contract_metadata(&self) -> (String, String) {
+            (
+                String::from(env!("CARGO_PKG_NAME")),
+                String::from(env!("CARGO_PKG_VERSION")),
+            )
+        }
+    }
 }
@@ -219,7 +219,7 @@ mod lpt {
 
         /// Issue a new amount of tokens
         /// these tokens are deposited into the owner address
-        #[ink(message)]
+        #[ink(message, selector = 0xcfdd9aa2)]
         pub fn mint(&mut self, user: AccountId, amount: Balance) -> Result<()> {
             assert!(amount > 0);
 
@@ -238,7 +238,7 @@ mod lpt {
         /// These tokens are withdrawn from the owner address
         /// if the balance must be enough to cover the redeem
         /// or the call will fail.
-        #[ink(message)]
+        #[ink(message, selector = 0xb1efc17b)]
         pub fn burn(&mut self, user: AccountId, amount: Balance) -> Result<()> {
             if *self.total_supply < amount {
                 return Err(Error::InsufficientSupply);
@@ -288,4 +288,24 @@ mod lpt {
             Ok(())
         }
     }
+
+    impl Erc20 {
+        /// Returns the package version encoded as `major * 10000 + minor * 100 + patch`.
+        #[ink(message, selector = 0xec6d41e1)]
+        pub fn version(&self) -> u32 {
+            let part = |v: &str| v.parse::<u32>().unwrap_or(0);
+            part(env!("CARGO_PKG_VERSION_MAJOR")) * 10_000
+                + part(env!("CARGO_PKG_VERSION_MINOR")) * 100
+                + part(env!("CARGO_PKG_VERSION_PATCH"))
+        }
+
+        /// Returns the contract name and its package version.
+        #[ink(message, selector = 0x666ca1c6)]
+        pub fn contract_metadata(&self) -> (String, String) {
+            (
+                String::from(env!("CARGO_PKG_NAME")),
+                String::from(env!("CARGO_PKG_VERSION")),
+            )
+        }
+    }
 }
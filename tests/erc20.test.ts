@@ -96,4 +96,35 @@ describe('ERC20', () => {
     const result = await contract.query['iErc20,balanceOf'](receiver.address);
     expect(result.output).to.equal(7);
   });
+
+  it('Reports version and metadata of a trait-based token', async () => {
+    const { sender } = await setup();
+    const issueFactory = await getContractFactory('erc20_issue', sender);
+    const contract = await issueFactory.deploy('IErc20,new', '1000000', 'Jupiter Token', 'JPT', '10');
+
+    const version = await contract.query.version();
+    expect(version.output?.toString()).to.equal('100');
+    const metadata = await contract.query.contractMetadata();
+    expect(metadata.output?.toHuman()).to.deep.equal(['erc20_issue', '0.1.0']);
+  });
+
+  it('Pausing transfers keeps minting available', async () => {
+    const { sender, receiver } = await setup();
+    const issueFactory = await getContractFactory('erc20_issue', sender);
+    const contract = await issueFactory.deploy('IErc20,new', '1000000', 'Jupiter Token', 'JPT', '10');
+
+    // PAUSE_TRANSFER
+    await expect(contract.tx['Pausable,pauseOps'](1)).to.emit(contract, 'Pause').withArgs(1);
+    const pausedOps = await contract.query['Pausable,pausedOps']();
+    expect(pausedOps.output?.toString()).to.equal('1');
+
+    await expect(contract.tx['IErc20,transfer'](receiver.address, 7)).to.not.emit(
+      contract,
+      'Transfer'
+    );
+    await expect(contract.tx.mint(receiver.address, 7)).to.emit(contract, 'Mint');
+
+    await contract.tx['Pausable,unpauseOps'](1);
+    await expect(contract.tx['IErc20,transfer'](receiver.address, 7)).to.emit(contract, 'Transfer');
+  });
 });
@@ -30,4 +30,12 @@ describe('PatraPixel', () => {
       value: 300000000000
     })).to.emit(contract, 'PixelUpdate');
   });
+
+  it('reports version and metadata', async () => {
+    const { contract } = await setup();
+    const version = await contract.query.version();
+    expect(version.output?.toString()).to.equal('100');
+    const metadata = await contract.query.contractMetadata();
+    expect(metadata.output?.toHuman()).to.deep.equal(['patrapixel', '0.1.0']);
+  });
 });
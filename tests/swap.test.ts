@@ -2,7 +2,7 @@ import BN from 'bn.js';
 import { expect } from 'chai';
 import { patract, network, artifacts } from 'redspot';
 
-const { getContractFactory, getRandomSigner } = patract;
+const { getContractAt, getContractFactory, getRandomSigner } = patract;
 
 const { api, getSigners } = network;
 
@@ -41,9 +41,156 @@ describe('PatraSwap', () => {
     return { sender, contractFactory, contract, abi, receiver, Alice, one, ethContract, btcContract };
   }
 
+  async function setupExchange() {
+    const ctx = await setup();
+    const { contract, sender, ethContract, btcContract } = ctx;
+
+    await contract.tx['factory,createExchange'](ethContract.address, btcContract.address, undefined);
+    const result = await contract.query['factory,getExchange'](ethContract.address, btcContract.address);
+    // @ts-ignore
+    const exchange = await getContractAt('exchange', result.output.unwrap(), sender);
+
+    await ethContract.tx['IErc20,approve'](exchange.address, '100000000000000');
+    await btcContract.tx['IErc20,approve'](exchange.address, '100000000000000');
+    await exchange.tx.addLiquidity('1000000000', '1000000000');
+
+    return { ...ctx, exchange };
+  }
+
+  async function setupExchange2() {
+    const ctx = await setup();
+    const { contract, sender, ethContract, one } = ctx;
+
+    await contract.tx['factory,createExchangeWithDot'](ethContract.address, undefined);
+    const dot = api.createType('AccountId', new Uint8Array(32));
+    const result = await contract.query['factory,getExchange'](ethContract.address, dot);
+    // @ts-ignore
+    const exchange = await getContractAt('exchange2', result.output.unwrap(), sender);
+
+    await ethContract.tx['IErc20,approve'](exchange.address, '100000000000000');
+    await exchange.tx.addLiquidity('1000000000', { value: one.muln(10) });
+
+    return { ...ctx, exchange };
+  }
+
+  function inTenMinutes() {
+    return Date.now() + 10 * 60 * 1000;
+  }
+
   it('create exchange', async () => {
     const { contract, ethContract, btcContract } = await setup();
 
     await contract.tx['factory,createExchange'](ethContract.address, btcContract.address, undefined);
   });
+
+  it('swap input reverts below minimum output', async () => {
+    const { exchange } = await setupExchange();
+
+    const quote = await exchange.query.getFromSwapToInputPrice('1000000');
+    const minBought = new BN(quote.output!.toString()).addn(1);
+
+    await expect(exchange.tx.swapFromToInput('1000000', minBought, inTenMinutes())).to.not.emit(
+      exchange,
+      'TokenSwap'
+    );
+  });
+
+  it('swap output reverts above maximum input', async () => {
+    const { exchange } = await setupExchange();
+
+    const quote = await exchange.query.getFromSwapToOutputPrice('1000000');
+    const maxSold = new BN(quote.output!.toString()).subn(1);
+
+    await expect(exchange.tx.swapFromToOutput('1000000', maxSold, inTenMinutes())).to.not.emit(
+      exchange,
+      'TokenSwap'
+    );
+  });
+
+  it('swap reverts after deadline', async () => {
+    const { exchange } = await setupExchange();
+
+    await expect(exchange.tx.swapToFromInput('1000000', 0, 1)).to.not.emit(exchange, 'TokenSwap');
+  });
+
+  it('swap succeeds at exactly the quoted bound', async () => {
+    const { exchange } = await setupExchange();
+
+    const inputQuote = await exchange.query.getToSwapFromInputPrice('1000000');
+    await expect(
+      exchange.tx.swapToFromInput('1000000', inputQuote.output!.toString(), inTenMinutes())
+    ).to.emit(exchange, 'TokenSwap');
+
+    const outputQuote = await exchange.query.getToSwapFromOutputPrice('1000000');
+    await expect(
+      exchange.tx.swapToFromOutput('1000000', outputQuote.output!.toString(), inTenMinutes())
+    ).to.emit(exchange, 'TokenSwap');
+  });
+
+  it('dot swap input reverts below minimum output', async () => {
+    const { exchange, one } = await setupExchange2();
+
+    const quote = await exchange.query.getDotToTokenInputPrice(one);
+    const minTokens = new BN(quote.output!.toString()).addn(1);
+
+    await expect(
+      exchange.tx.swapDotToTokenInput(minTokens, inTenMinutes(), { value: one })
+    ).to.not.emit(exchange, 'TokenSwap');
+  });
+
+  it('dot swap output reverts when transferred value is below the quote', async () => {
+    const { exchange } = await setupExchange2();
+
+    const quote = await exchange.query.getDotToTokenOutputPrice('1000000');
+    const value = new BN(quote.output!.toString()).subn(1);
+
+    await expect(
+      exchange.tx.swapDotToTokenOutput('1000000', inTenMinutes(), { value })
+    ).to.not.emit(exchange, 'TokenSwap');
+  });
+
+  it('token to dot swaps revert below minimum output or after deadline', async () => {
+    const { exchange } = await setupExchange2();
+
+    const quote = await exchange.query.getTokenToDotInputPrice('1000000');
+    const minDot = new BN(quote.output!.toString()).addn(1);
+
+    await expect(
+      exchange.tx.swapTokenToDotInput('1000000', minDot, inTenMinutes())
+    ).to.not.emit(exchange, 'TokenSwap');
+    await expect(exchange.tx.swapTokenToDotOutput('1000000', '1000000000', 1)).to.not.emit(
+      exchange,
+      'TokenSwap'
+    );
+  });
+
+  it('dot swaps succeed at exactly the quoted bound', async () => {
+    const { exchange, one } = await setupExchange2();
+
+    const inputQuote = await exchange.query.getDotToTokenInputPrice(one);
+    await expect(
+      exchange.tx.swapDotToTokenInput(inputQuote.output!.toString(), inTenMinutes(), { value: one })
+    ).to.emit(exchange, 'TokenSwap');
+
+    const outputQuote = await exchange.query.getDotToTokenOutputPrice('1000000');
+    await expect(
+      exchange.tx.swapDotToTokenOutput('1000000', inTenMinutes(), {
+        value: outputQuote.output!.toString()
+      })
+    ).to.emit(exchange, 'TokenSwap');
+
+    const tokenQuote = await exchange.query.getTokenToDotOutputPrice('1000000');
+    await expect(
+      exchange.tx.swapTokenToDotOutput('1000000', tokenQuote.output!.toString(), inTenMinutes())
+    ).to.emit(exchange, 'TokenSwap');
+  });
+
+  it('exchange reports version and metadata', async () => {
+    const { exchange } = await setupExchange();
+
+    const version = await exchange.query.version();
+    expect(version.output?.toString()).to.equal('100');
+    const metadata = await exchange.query.contractMetadata();
+    expect(metadata.output?.toHuman()).to.deep.equal(['exchange', '0.1.0']);
+  });
 });